@@ -0,0 +1,196 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::ent::{self, RecaptchaEntError, RecaptchaEntResult};
+use crate::token_store::TokenStore;
+use crate::v3::{self, RecaptchaError, RecaptchaResult};
+
+/// Initial replay TTL of a new client. [`RecaptchaClient::with_token_store`] always replaces it
+/// with the TTL passed alongside the store.
+const DEFAULT_REPLAY_TTL: Duration = Duration::from_secs(120);
+
+/// A reusable reCAPTCHA client.
+///
+/// The free functions (`verify_v3`, `verify_enterprise`, ...) build a fresh `reqwest::Client`
+/// on every call, which re-does connection pooling and TLS setup each time. `RecaptchaClient`
+/// holds a single pooled `reqwest::Client` plus your credentials so repeated verifications
+/// reuse it. The base URL can also be overridden, which is mainly useful for pointing the
+/// client at a mock server in tests instead of Google.
+pub struct RecaptchaClient {
+    http: reqwest::Client,
+    secret: Option<String>,
+    api_key: Option<String>,
+    project: Option<String>,
+    site_key: Option<String>,
+    base_url: Option<String>,
+    token_store: Option<Arc<dyn TokenStore>>,
+    replay_ttl: Duration,
+}
+
+impl RecaptchaClient {
+    /// Build a client for verifying v2/v3 tokens with the given secret.
+    pub fn new_v3(secret: impl Into<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            secret: Some(secret.into()),
+            api_key: None,
+            project: None,
+            site_key: None,
+            base_url: None,
+            token_store: None,
+            replay_ttl: DEFAULT_REPLAY_TTL,
+        }
+    }
+
+    /// Build a client for verifying Enterprise assessments.
+    pub fn new_enterprise(
+        project: impl Into<String>,
+        api_key: impl Into<String>,
+        site_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            secret: None,
+            api_key: Some(api_key.into()),
+            project: Some(project.into()),
+            site_key: Some(site_key.into()),
+            base_url: None,
+            token_store: None,
+            replay_ttl: DEFAULT_REPLAY_TTL,
+        }
+    }
+
+    /// Override the request timeout of the pooled `reqwest::Client`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Result<Self, RecaptchaError> {
+        self.http = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(RecaptchaError::HttpError)?;
+        Ok(self)
+    }
+
+    /// Override the base URL requests are sent to, e.g. to point at a mock server in tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Reject tokens that have already been consumed within `ttl`, using `store` to remember
+    /// them. This guards against a token being replayed before Google's own
+    /// `timeout-or-duplicate` check kicks in.
+    pub fn with_token_store(mut self, store: impl TokenStore + 'static, ttl: Duration) -> Self {
+        self.token_store = Some(Arc::new(store));
+        self.replay_ttl = ttl;
+        self
+    }
+
+    /// See [`crate::verify_v3`].
+    pub async fn verify_v3(
+        &self,
+        response: &str,
+        remoteip: Option<&IpAddr>,
+    ) -> Result<(), RecaptchaError> {
+        self.verify_v3_detailed(response, remoteip).await.map(|_| ())
+    }
+
+    /// See [`crate::verify_v3_detailed`].
+    pub async fn verify_v3_detailed(
+        &self,
+        response: &str,
+        remoteip: Option<&IpAddr>,
+    ) -> Result<RecaptchaResult, RecaptchaError> {
+        if let Some(store) = &self.token_store {
+            if store.seen(response).await {
+                return Err(RecaptchaError::ReplayDetected);
+            }
+        }
+
+        let secret = self.secret.as_deref().unwrap_or_default();
+        let base_url = self.base_url.as_deref().unwrap_or(v3::POST_URL);
+
+        let result =
+            v3::verify_v3_detailed_with(&self.http, base_url, secret, response, remoteip).await?;
+
+        if let Some(store) = &self.token_store {
+            store.remember(response, self.replay_ttl).await;
+        }
+
+        Ok(result)
+    }
+
+    /// See [`crate::verify_v3_with_threshold`].
+    pub async fn verify_v3_with_threshold(
+        &self,
+        response: &str,
+        remoteip: Option<&IpAddr>,
+        min_score: f32,
+        expected_action: Option<&str>,
+    ) -> Result<RecaptchaResult, RecaptchaError> {
+        let result = self.verify_v3_detailed(response, remoteip).await?;
+
+        if let Some(score) = result.score {
+            if score < min_score {
+                return Err(RecaptchaError::InsufficientScore(score));
+            }
+        }
+
+        if let Some(expected_action) = expected_action {
+            if result.action.as_deref() != Some(expected_action) {
+                return Err(RecaptchaError::ActionMismatch(
+                    result.action.clone().unwrap_or_default(),
+                ));
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// See [`crate::verify_enterprise`].
+    pub async fn verify_enterprise(
+        &self,
+        token: &str,
+        action: Option<&str>,
+    ) -> Result<(), RecaptchaEntError> {
+        let result = self.verify_enterprise_detailed(token, action).await?;
+
+        if result.token_properties.valid {
+            Ok(())
+        } else if let Some(reason) = result.token_properties.invalid_reason {
+            Err(RecaptchaEntError::InvalidReason(reason))
+        } else {
+            Err(RecaptchaEntError::UnknownReason)
+        }
+    }
+
+    /// See [`crate::verify_enterprise_detailed`].
+    pub async fn verify_enterprise_detailed(
+        &self,
+        token: &str,
+        action: Option<&str>,
+    ) -> Result<RecaptchaEntResult, RecaptchaEntError> {
+        if let Some(store) = &self.token_store {
+            if store.seen(token).await {
+                return Err(RecaptchaEntError::ReplayDetected);
+            }
+        }
+
+        let project = self.project.as_deref().unwrap_or_default();
+        let api_key = self.api_key.as_deref().unwrap_or_default();
+        let site_key = self.site_key.as_deref().unwrap_or_default();
+        let base_url = self.base_url.as_deref().unwrap_or(ent::ENTERPRISE_BASE_URL);
+
+        let result = ent::verify_enterprise_detailed_with(
+            &self.http, base_url, project, api_key, site_key, token, action,
+        )
+        .await?;
+
+        if result.token_properties.valid {
+            if let Some(store) = &self.token_store {
+                store.remember(token, self.replay_ttl).await;
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::HashMap;
 
+pub(crate) const ENTERPRISE_BASE_URL: &str = "https://recaptchaenterprise.googleapis.com";
+
 /// Error returned when ReCaptcha verification fails
 #[derive(Debug)]
 pub enum RecaptchaEntError {
@@ -11,6 +13,8 @@ pub enum RecaptchaEntError {
     HttpError(reqwest::Error),
     DecodingError(serde_json::Error),
     UnexpectedResponse(String, String, String),
+    /// The token was already seen by the [`crate::RecaptchaClient`]'s [`crate::TokenStore`].
+    ReplayDetected,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,6 +70,42 @@ pub struct RecaptchaEntResult {
     pub token_properties: RecaptchaEntTokenProps,
 }
 
+/// Whether the assessed action turned out to be legitimate or fraudulent, reported back to
+/// Google via [`annotate_assessment`] so it can tune the risk model.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecaptchaEntAnnotation {
+    Legitimate,
+    Fraudulent,
+    PasswordCorrect,
+    PasswordIncorrect,
+}
+
+/// Additional context for a [`RecaptchaEntAnnotation`], passed alongside it to
+/// [`annotate_assessment`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecaptchaEntReason {
+    Chargeback,
+    ChargebackFraud,
+    ChargebackDispute,
+    Refund,
+    RefundFraud,
+    TransactionAccepted,
+    TransactionDeclined,
+    PaymentHeuristics,
+    InitiatedTwoFactor,
+    PassedTwoFactor,
+    FailedTwoFactor,
+    CorrectPassword,
+    IncorrectPassword,
+    SocialSpam,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct RecaptchaEntAnnotateResult {}
+
 pub async fn verify_enterprise(
     project: &str,
     api_key: &str,
@@ -90,6 +130,31 @@ pub async fn verify_enterprise_detailed(
     site_key: &str,
     token: &str,
     action: Option<&str>,
+) -> Result<RecaptchaEntResult, RecaptchaEntError> {
+    verify_enterprise_detailed_with(
+        &reqwest::Client::new(),
+        ENTERPRISE_BASE_URL,
+        project,
+        api_key,
+        site_key,
+        token,
+        action,
+    )
+    .await
+}
+
+/// Same as [`verify_enterprise_detailed`] but against a caller-supplied `reqwest::Client` and
+/// base URL, so [`crate::RecaptchaClient`] can reuse a single pooled client instead of building
+/// one per call.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn verify_enterprise_detailed_with(
+    http: &reqwest::Client,
+    base_url: &str,
+    project: &str,
+    api_key: &str,
+    site_key: &str,
+    token: &str,
+    action: Option<&str>,
 ) -> Result<RecaptchaEntResult, RecaptchaEntError> {
     let request = json!({
         "event": {
@@ -99,9 +164,10 @@ pub async fn verify_enterprise_detailed(
         }
     });
 
-    let client = reqwest::Client::new();
-    let response = client
-        .post(format!("https://recaptchaenterprise.googleapis.com/v1/projects/{project}/assessments?key={api_key}"))
+    let response = http
+        .post(format!(
+            "{base_url}/v1/projects/{project}/assessments?key={api_key}"
+        ))
         .json(&request)
         .send()
         .await
@@ -128,3 +194,85 @@ pub async fn verify_enterprise_detailed(
         }
     }
 }
+
+/// # Report back whether an assessment was legitimate or fraudulent
+///
+/// Calls the Enterprise `annotateAssessment` endpoint, which lets you tell Google whether an
+/// assessed action turned out to be legitimate or fraudulent so it can tune the risk model.
+/// `assessment_name` is the `name` field from the [`RecaptchaEntResult`] returned by
+/// [`verify_enterprise_detailed`], e.g. `"projects/my-project/assessments/abc123"`. Note that
+/// Google may echo `name` with the project *number* rather than the *ID* passed as `project`,
+/// so `assessment_name` alone (not `project`) determines which resource is annotated.
+pub async fn annotate_assessment(
+    project: &str,
+    api_key: &str,
+    assessment_name: &str,
+    annotation: RecaptchaEntAnnotation,
+    reasons: Vec<RecaptchaEntReason>,
+) -> Result<(), RecaptchaEntError> {
+    annotate_assessment_with(
+        &reqwest::Client::new(),
+        ENTERPRISE_BASE_URL,
+        project,
+        api_key,
+        assessment_name,
+        annotation,
+        reasons,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn annotate_assessment_with(
+    http: &reqwest::Client,
+    base_url: &str,
+    project: &str,
+    api_key: &str,
+    assessment_name: &str,
+    annotation: RecaptchaEntAnnotation,
+    reasons: Vec<RecaptchaEntReason>,
+) -> Result<(), RecaptchaEntError> {
+    // Google echoes `name` with the project *number*, which commonly differs from the
+    // project *ID* callers pass here, so this is a best-effort sanity check rather than a
+    // hard validation: the endpoint works from `assessment_name` alone regardless of `project`.
+    if cfg!(debug_assertions) && !assessment_name.contains(project) {
+        eprintln!(
+            "assessment_name {assessment_name:?} doesn't look like it belongs to project {project:?}"
+        );
+    }
+
+    let request = json!({
+        "annotation": annotation,
+        "reasons": reasons,
+    });
+
+    let response = http
+        .post(format!(
+            "{base_url}/v1/{assessment_name}:annotate?key={api_key}"
+        ))
+        .json(&request)
+        .send()
+        .await
+        .map_err(RecaptchaEntError::HttpError)?;
+
+    let response_body = response
+        .text()
+        .await
+        .map_err(RecaptchaEntError::HttpError)?;
+
+    match serde_json::from_str::<RecaptchaEntAnnotateResult>(&response_body) {
+        Ok(_) => Ok(()),
+        Err(result_decoding) => {
+            match serde_json::from_str::<RecaptchaEntApiResponse>(&response_body)
+                .map_err(RecaptchaEntError::DecodingError)
+            {
+                Ok(err_response) => Err(RecaptchaEntError::ApiError(err_response)),
+                Err(err_decoding) => Err(RecaptchaEntError::UnexpectedResponse(
+                    response_body,
+                    format!("Error while parsing result response: {:?}", result_decoding),
+                    format!("Error while parsing error response: {:?}", err_decoding),
+                )),
+            }
+        }
+    }
+}
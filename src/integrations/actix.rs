@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::{web, FromRequest, HttpRequest, HttpResponse, ResponseError};
+
+use super::{RecaptchaGuardConfig, RecaptchaGuardError, RecaptchaTokenSource};
+use crate::RecaptchaClient;
+
+impl std::fmt::Display for RecaptchaGuardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl ResponseError for RecaptchaGuardError {
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::Forbidden().body("reCAPTCHA verification failed")
+    }
+}
+
+/// An extractor that verifies the reCAPTCHA token on an incoming request against the
+/// [`RecaptchaClient`] and [`RecaptchaGuardConfig`] stored in application data, yielding the
+/// resulting [`crate::RecaptchaResult`] on success.
+///
+/// ```ignore
+/// async fn protected(guard: RecaptchaGuard) -> impl Responder {
+///     format!("score: {:?}", guard.0.score)
+/// }
+/// ```
+pub struct RecaptchaGuard(pub crate::RecaptchaResult);
+
+/// `ConnectionInfo::realip_remote_addr` returns the forwarded IP when behind a proxy, but on a
+/// direct connection it falls back to the raw peer address, which carries a `:port` suffix that
+/// `IpAddr::from_str` rejects. Strip it before parsing.
+fn parse_remote_ip(raw: &str) -> Option<IpAddr> {
+    raw.parse::<IpAddr>()
+        .ok()
+        .or_else(|| raw.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+}
+
+impl FromRequest for RecaptchaGuard {
+    type Error = RecaptchaGuardError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        let mut payload = payload.take();
+
+        Box::pin(async move {
+            let config = req
+                .app_data::<web::Data<RecaptchaGuardConfig>>()
+                .ok_or(RecaptchaGuardError::MissingConfig)?
+                .clone();
+            let client = req
+                .app_data::<web::Data<RecaptchaClient>>()
+                .ok_or(RecaptchaGuardError::MissingClient)?
+                .clone();
+
+            let token = match &config.token_source {
+                RecaptchaTokenSource::Header(name) => req
+                    .headers()
+                    .get(name.as_str())
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| value.to_string())
+                    .ok_or(RecaptchaGuardError::MissingToken)?,
+                RecaptchaTokenSource::FormField(field) => {
+                    let form = web::Form::<HashMap<String, String>>::from_request(&req, &mut payload)
+                        .await
+                        .map_err(|_| RecaptchaGuardError::MissingToken)?;
+
+                    form.get(field)
+                        .cloned()
+                        .ok_or(RecaptchaGuardError::MissingToken)?
+                }
+            };
+
+            let remoteip = req
+                .connection_info()
+                .realip_remote_addr()
+                .and_then(parse_remote_ip);
+
+            let result = client
+                .verify_v3_with_threshold(
+                    &token,
+                    remoteip.as_ref(),
+                    config.min_score,
+                    config.expected_action.as_deref(),
+                )
+                .await
+                .map_err(RecaptchaGuardError::Verification)?;
+
+            Ok(RecaptchaGuard(result))
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::extract::connect_info::ConnectInfo;
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::{async_trait, Form};
+
+use super::{RecaptchaGuardConfig, RecaptchaGuardError, RecaptchaTokenSource};
+use crate::RecaptchaClient;
+
+impl IntoResponse for RecaptchaGuardError {
+    fn into_response(self) -> Response {
+        (StatusCode::FORBIDDEN, "reCAPTCHA verification failed").into_response()
+    }
+}
+
+/// An extractor that verifies the reCAPTCHA token on an incoming request against the
+/// [`RecaptchaClient`] and [`RecaptchaGuardConfig`] pulled from router state, yielding the
+/// resulting [`crate::RecaptchaResult`] on success.
+///
+/// ```ignore
+/// async fn protected(RecaptchaGuard(result): RecaptchaGuard) -> impl IntoResponse {
+///     format!("score: {:?}", result.score)
+/// }
+/// ```
+pub struct RecaptchaGuard(pub crate::RecaptchaResult);
+
+#[async_trait]
+impl<S> FromRequest<S> for RecaptchaGuard
+where
+    S: Send + Sync,
+    Arc<RecaptchaClient>: FromRef<S>,
+    Arc<RecaptchaGuardConfig>: FromRef<S>,
+{
+    type Rejection = RecaptchaGuardError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let config = Arc::<RecaptchaGuardConfig>::from_ref(state);
+        let client = Arc::<RecaptchaClient>::from_ref(state);
+
+        let remoteip = req
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|connect_info| connect_info.0.ip());
+
+        let token = match &config.token_source {
+            RecaptchaTokenSource::Header(name) => req
+                .headers()
+                .get(name.as_str())
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_string())
+                .ok_or(RecaptchaGuardError::MissingToken)?,
+            RecaptchaTokenSource::FormField(field) => {
+                let Form(form) = Form::<HashMap<String, String>>::from_request(req, state)
+                    .await
+                    .map_err(|_| RecaptchaGuardError::MissingToken)?;
+
+                form.get(field)
+                    .cloned()
+                    .ok_or(RecaptchaGuardError::MissingToken)?
+            }
+        };
+
+        let result = client
+            .verify_v3_with_threshold(
+                &token,
+                remoteip.as_ref(),
+                config.min_score,
+                config.expected_action.as_deref(),
+            )
+            .await
+            .map_err(RecaptchaGuardError::Verification)?;
+
+        Ok(RecaptchaGuard(result))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::v3::RecaptchaError;
+
+/// Where to read the reCAPTCHA token from on an incoming request.
+#[derive(Debug, Clone)]
+pub enum RecaptchaTokenSource {
+    /// Read the token from this request header.
+    Header(String),
+    /// Read the token from this field of an `application/x-www-form-urlencoded` body.
+    FormField(String),
+}
+
+/// Configuration for the framework guards, expected to live in application state alongside
+/// the [`crate::RecaptchaClient`] it verifies against.
+#[derive(Debug, Clone)]
+pub struct RecaptchaGuardConfig {
+    pub token_source: RecaptchaTokenSource,
+    pub min_score: f32,
+    pub expected_action: Option<String>,
+}
+
+/// Rejection returned when a guard fails to extract or verify a token.
+#[derive(Debug)]
+pub enum RecaptchaGuardError {
+    /// No [`RecaptchaGuardConfig`] was found in application state.
+    MissingConfig,
+    /// No [`crate::RecaptchaClient`] was found in application state.
+    MissingClient,
+    /// The token was missing from the configured header or form field.
+    MissingToken,
+    /// Verification against `RecaptchaClient::verify_v3_with_threshold` failed.
+    Verification(RecaptchaError),
+}
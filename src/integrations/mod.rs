@@ -0,0 +1,9 @@
+mod config;
+
+pub use config::{RecaptchaGuardConfig, RecaptchaGuardError, RecaptchaTokenSource};
+
+#[cfg(feature = "actix")]
+pub mod actix;
+
+#[cfg(feature = "axum")]
+pub mod axum;
@@ -1,16 +1,31 @@
+mod client;
 mod ent;
+#[cfg(any(feature = "actix", feature = "axum"))]
+pub mod integrations;
+mod token_store;
 mod v3;
 
 #[allow(deprecated)]
 pub use v3::verify;
 pub use v3::verify_v3;
+pub use v3::verify_v3_detailed;
+pub use v3::verify_v3_with_threshold;
 pub use v3::RecaptchaError;
+pub use v3::RecaptchaResult;
 
+pub use ent::annotate_assessment;
 pub use ent::verify_enterprise;
 pub use ent::verify_enterprise_detailed;
+pub use ent::RecaptchaEntAnnotation;
 pub use ent::RecaptchaEntError;
+pub use ent::RecaptchaEntReason;
 pub use ent::RecaptchaEntResult;
 
+pub use client::RecaptchaClient;
+
+pub use token_store::InMemoryTokenStore;
+pub use token_store::TokenStore;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +115,91 @@ mod tests {
             matches!(result, Err(RecaptchaEntError::ApiError(api_error)) if api_error.error.code >= 400)
         );
     }
+
+    #[tokio::test]
+    async fn recaptcha_client_verify_v3_against_mock_server() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/siteverify"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "score": 0.9,
+                "action": "login",
+                "challenge_ts": "2024-01-01T00:00:00Z",
+                "hostname": "example.com",
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = RecaptchaClient::new_v3("secret")
+            .with_base_url(format!("{}/siteverify", mock_server.uri()));
+
+        let result = client.verify_v3_detailed("token", None).await.unwrap();
+        assert_eq!(result.score, Some(0.9));
+
+        assert!(matches!(
+            client.verify_v3_with_threshold("token", None, 0.95, None).await,
+            Err(RecaptchaError::InsufficientScore(score)) if score == 0.9
+        ));
+
+        assert!(matches!(
+            client
+                .verify_v3_with_threshold("token", None, 0.5, Some("signup"))
+                .await,
+            Err(RecaptchaError::ActionMismatch(action)) if action == "login"
+        ));
+    }
+
+    #[tokio::test]
+    async fn recaptcha_client_rejects_replayed_token() {
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/siteverify"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "success": true })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = RecaptchaClient::new_v3("secret")
+            .with_base_url(format!("{}/siteverify", mock_server.uri()))
+            .with_token_store(InMemoryTokenStore::new(), Duration::from_secs(60));
+
+        assert!(matches!(client.verify_v3("token", None).await, Ok(())));
+        assert!(matches!(
+            client.verify_v3("token", None).await,
+            Err(RecaptchaError::ReplayDetected)
+        ));
+    }
+
+    #[tokio::test]
+    async fn verify_v3_detailed_maps_malformed_body_to_http_error() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/siteverify"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+            .mount(&mock_server)
+            .await;
+
+        let client = RecaptchaClient::new_v3("secret")
+            .with_base_url(format!("{}/siteverify", mock_server.uri()));
+
+        assert!(matches!(
+            client.verify_v3_detailed("token", None).await,
+            Err(RecaptchaError::HttpError(_))
+        ));
+    }
 }
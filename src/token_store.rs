@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Tracks which reCAPTCHA tokens have already been consumed, to close the race where a token
+/// is replayed before Google's own `timeout-or-duplicate` check catches it.
+///
+/// reCAPTCHA tokens are meant to be single-use, but there's a window between a token first
+/// being verified and Google invalidating it server-side. Implement this trait against Redis,
+/// a database, or anything else with TTL support to close that window across processes;
+/// [`InMemoryTokenStore`] is provided for single-process deployments.
+///
+/// Note: `seen` and `remember` are separate calls, not a single atomic check-and-set. Two
+/// concurrent requests replaying the same token can both observe `seen` return `false` before
+/// either calls `remember`, so this does not fully close the race under concurrency —
+/// implementations that need that guarantee should back `seen`+`remember` with an atomic
+/// operation (e.g. Redis `SET ... NX`) rather than relying on call order.
+#[async_trait::async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Returns `true` if `token` has already been recorded via [`TokenStore::remember`] and
+    /// hasn't expired yet.
+    async fn seen(&self, token: &str) -> bool;
+
+    /// Records `token` as consumed for `ttl`.
+    async fn remember(&self, token: &str, ttl: Duration);
+}
+
+/// A `HashMap`-backed [`TokenStore`] for single-process deployments.
+#[derive(Default)]
+pub struct InMemoryTokenStore {
+    seen: Mutex<HashMap<String, Instant>>,
+}
+
+impl InMemoryTokenStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drops expired entries. `seen` only prunes the single token it looked up, so without
+    /// this a token that's remembered once and never looked up again would live in the map
+    /// forever; `remember` calls this opportunistically so the map stays bounded even then.
+    fn evict_expired(seen: &mut HashMap<String, Instant>) {
+        let now = Instant::now();
+        seen.retain(|_, expires_at| *expires_at > now);
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStore for InMemoryTokenStore {
+    async fn seen(&self, token: &str) -> bool {
+        let mut seen = self.seen.lock().expect("token store mutex poisoned");
+
+        match seen.get(token) {
+            Some(expires_at) if *expires_at > Instant::now() => true,
+            Some(_) => {
+                seen.remove(token);
+                false
+            }
+            None => false,
+        }
+    }
+
+    async fn remember(&self, token: &str, ttl: Duration) {
+        let mut seen = self.seen.lock().expect("token store mutex poisoned");
+        Self::evict_expired(&mut seen);
+        seen.insert(token.to_string(), Instant::now() + ttl);
+    }
+}
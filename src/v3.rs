@@ -1,7 +1,7 @@
 use serde::Deserialize;
 use std::net::IpAddr;
 
-const POST_URL: &str = "https://www.google.com/recaptcha/api/siteverify";
+pub(crate) const POST_URL: &str = "https://www.google.com/recaptcha/api/siteverify";
 
 /// Error returned when ReCaptcha verification fails
 #[derive(Debug)]
@@ -14,6 +14,15 @@ pub enum RecaptchaError {
     InvalidInputResponse,
     BadRequest,
     TimeoutOrDuplicate,
+    /// The returned score was below the `min_score` passed to `verify_v3_with_threshold`.
+    InsufficientScore(f32),
+    /// The returned action did not match the `expected_action` passed to `verify_v3_with_threshold`.
+    ActionMismatch(String),
+    /// The token was already seen by the [`crate::RecaptchaClient`]'s [`crate::TokenStore`].
+    ReplayDetected,
+    /// Google reported more than one `error-codes` entry; each is mapped individually instead
+    /// of discarding all but the first.
+    Multiple(Vec<RecaptchaError>),
 }
 
 impl TryFrom<String> for RecaptchaError {
@@ -32,11 +41,15 @@ impl TryFrom<String> for RecaptchaError {
     }
 }
 
+/// Detailed result of a v3 verification, including the risk score and the
+/// context Google returns alongside it.
 #[derive(Deserialize, Debug, Clone)]
-struct RecaptchaResult {
-    success: bool,
-    // challenge_ts: Option<String>,
-    // hostname: Option<String>,
+pub struct RecaptchaResult {
+    pub success: bool,
+    pub score: Option<f32>,
+    pub action: Option<String>,
+    pub challenge_ts: Option<String>,
+    pub hostname: Option<String>,
     // apk_package_name: Option<String>,
     #[serde(rename(deserialize = "error-codes"))]
     error_codes: Option<Vec<String>>,
@@ -96,6 +109,44 @@ pub async fn verify_v3(
     response: &str,
     remoteip: Option<&IpAddr>,
 ) -> Result<(), RecaptchaError> {
+    verify_v3_detailed(secret, response, remoteip)
+        .await
+        .map(|_| ())
+}
+
+/// # Verify ReCaptcha V3 and get the full result back
+///
+/// Unlike [`verify_v3`], which only tells you whether the token was valid, this returns
+/// the `score`, `action`, `challenge_ts` and `hostname` Google reports alongside it, so
+/// callers can make their own risk decision instead of relying on a bare pass/fail.
+///
+/// ```ignore
+/// use recaptcha_verify::{RecaptchaError, verify_v3_detailed};
+///
+/// let res = verify_v3_detailed("secret", "token", None).await?;
+/// println!("score: {:?}", res.score);
+/// ```
+pub async fn verify_v3_detailed(
+    secret: &str,
+    response: &str,
+    remoteip: Option<&IpAddr>,
+) -> Result<RecaptchaResult, RecaptchaError> {
+    verify_v3_detailed_with(&reqwest::Client::new(), POST_URL, secret, response, remoteip).await
+}
+
+/// Same as [`verify_v3_detailed`] but against a caller-supplied `reqwest::Client` and base URL,
+/// so [`crate::RecaptchaClient`] can reuse a single pooled client instead of building one per call.
+///
+/// Note: an unparseable response body surfaces as [`RecaptchaError::HttpError`] rather than
+/// [`RecaptchaError::Unknown`], since it's a reqwest-level decode failure rather than Google
+/// reporting failure without an error code.
+pub(crate) async fn verify_v3_detailed_with(
+    http: &reqwest::Client,
+    base_url: &str,
+    secret: &str,
+    response: &str,
+    remoteip: Option<&IpAddr>,
+) -> Result<RecaptchaResult, RecaptchaError> {
     let mut params = vec![("secret", secret), ("response", response)];
 
     let ip_str;
@@ -104,27 +155,69 @@ pub async fn verify_v3(
         params.push(("remoteip", ip_str.as_str()));
     }
 
-    let client = reqwest::Client::new();
-    let response = client
-        .post(POST_URL)
+    let response = http
+        .post(base_url)
         .form(&params)
         .send()
         .await
         .map_err(RecaptchaError::HttpError)?;
 
-    if let Ok(result) = response.json::<RecaptchaResult>().await {
-        if result.success {
-            return Ok(());
-        } else if let Some(errs) = result.error_codes {
-            return Err(errs
-                .first()
-                .ok_or(RecaptchaError::Unknown(None))?
-                .to_string()
-                .try_into()?);
+    let result = response
+        .json::<RecaptchaResult>()
+        .await
+        .map_err(RecaptchaError::HttpError)?;
+
+    if result.success {
+        return Ok(result);
+    }
+
+    match result.error_codes {
+        Some(errs) if !errs.is_empty() => {
+            let mut mapped = errs
+                .into_iter()
+                .map(RecaptchaError::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if mapped.len() == 1 {
+                Err(mapped.remove(0))
+            } else {
+                Err(RecaptchaError::Multiple(mapped))
+            }
+        }
+        _ => Err(RecaptchaError::Unknown(None)),
+    }
+}
+
+/// # Verify ReCaptcha V3 and enforce a minimum score / expected action
+///
+/// Convenience wrapper around [`verify_v3_detailed`] for the common case of rejecting
+/// low-trust tokens. Returns [`RecaptchaError::InsufficientScore`] when the returned
+/// `score` is below `min_score`, and [`RecaptchaError::ActionMismatch`] when
+/// `expected_action` is set but doesn't match the returned `action`.
+pub async fn verify_v3_with_threshold(
+    secret: &str,
+    response: &str,
+    remoteip: Option<&IpAddr>,
+    min_score: f32,
+    expected_action: Option<&str>,
+) -> Result<RecaptchaResult, RecaptchaError> {
+    let result = verify_v3_detailed(secret, response, remoteip).await?;
+
+    if let Some(score) = result.score {
+        if score < min_score {
+            return Err(RecaptchaError::InsufficientScore(score));
+        }
+    }
+
+    if let Some(expected_action) = expected_action {
+        if result.action.as_deref() != Some(expected_action) {
+            return Err(RecaptchaError::ActionMismatch(
+                result.action.clone().unwrap_or_default(),
+            ));
         }
     }
 
-    Err(RecaptchaError::Unknown(None))
+    Ok(result)
 }
 
 /// # Verify ReCaptcha